@@ -1,14 +1,56 @@
 use std::fs;
-use zed_extension_api::{self as zed, LanguageServerId, Result, Worktree};
+use zed_extension_api::{self as zed, settings::LspSettings, LanguageServerId, Result, Worktree};
+
+/// File that records the currently-installed lx-lsp release tag
+const INSTALLED_VERSION_FILE: &str = "lx-lsp-installed-version";
 
 struct LxExtension {
     cached_binary_path: Option<String>,
+    cached_version: Option<String>,
 }
 
 impl LxExtension {
     fn new() -> Self {
         Self {
             cached_binary_path: None,
+            cached_version: None,
+        }
+    }
+
+    /// Read the persisted installed release tag, if any
+    fn installed_version(&self) -> Option<String> {
+        if let Some(version) = &self.cached_version {
+            return Some(version.clone());
+        }
+
+        fs::read_to_string(INSTALLED_VERSION_FILE)
+            .ok()
+            .map(|contents| contents.trim().to_string())
+            .filter(|version| !version.is_empty())
+    }
+
+    /// Persist the installed release tag for future sessions
+    fn persist_installed_version(&self, version: &str) -> Result<()> {
+        fs::write(INSTALLED_VERSION_FILE, version)
+            .map_err(|e| format!("failed to persist installed lx-lsp version: {}", e))
+    }
+
+    /// Remove previously-downloaded version directories other than the one we just installed
+    fn prune_old_installs(&self, keep_dir: &str) {
+        let entries = match fs::read_dir(".") {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+
+        for entry in entries.flatten() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+
+            if is_dir && name.starts_with("lx-lsp-") && name != keep_dir {
+                eprintln!("[LX Extension] Pruning old lx-lsp install: {}", name);
+                let _ = fs::remove_dir_all(entry.path());
+            }
         }
     }
 
@@ -39,9 +81,126 @@ impl LxExtension {
         }
     }
 
+    /// Build the name of the release asset for the current platform, e.g.
+    /// `lx-lsp-linux-x86_64` or `lx-lsp-windows-aarch64.exe`.
+    fn release_asset_name(&self) -> String {
+        let (platform, arch) = zed::current_platform();
+
+        let os = match platform {
+            zed::Os::Mac => "darwin",
+            zed::Os::Linux => "linux",
+            zed::Os::Windows => "windows",
+        };
+
+        let arch = match arch {
+            zed::Architecture::Aarch64 => "aarch64",
+            zed::Architecture::X8664 => "x86_64",
+            zed::Architecture::X86 => "x86",
+        };
+
+        let extension = match platform {
+            zed::Os::Windows => ".exe",
+            _ => "",
+        };
+
+        format!("lx-lsp-{os}-{arch}{extension}")
+    }
+
+    /// Download a prebuilt lx-lsp binary from the latest GitHub release, or `Ok(None)` if no matching asset exists
+    fn install_prebuilt_binary(
+        &mut self,
+        language_server_id: &LanguageServerId,
+    ) -> Result<Option<String>> {
+        eprintln!("[LX Extension] Checking GitHub releases for a prebuilt lx-lsp binary...");
+
+        let release = zed::latest_github_release(
+            "kamal-hamza/lx-lsp",
+            zed::GithubReleaseOptions {
+                require_assets: true,
+                pre_release: false,
+            },
+        )?;
+
+        let asset_name = self.release_asset_name();
+        let asset = match release.assets.iter().find(|asset| asset.name == asset_name) {
+            Some(asset) => asset,
+            None => {
+                eprintln!(
+                    "[LX Extension] No release asset named {:?} found, falling back to go install",
+                    asset_name
+                );
+                return Ok(None);
+            }
+        };
+
+        let version_dir = format!("lx-lsp-{}", release.version);
+        let binary_path = format!("{}/{}", version_dir, self.get_binary_name());
+
+        let up_to_date = self.installed_version().as_deref() == Some(release.version.as_str())
+            && fs::metadata(&binary_path).is_ok();
+
+        if up_to_date {
+            eprintln!(
+                "[LX Extension] lx-lsp {} is already installed, skipping download",
+                release.version
+            );
+        } else {
+            eprintln!(
+                "[LX Extension] Downloading lx-lsp {} from {}",
+                release.version, asset.download_url
+            );
+
+            zed::set_language_server_installation_status(
+                language_server_id,
+                &zed::LanguageServerInstallationStatus::Downloading,
+            );
+
+            fs::create_dir_all(&version_dir)
+                .map_err(|e| format!("failed to create {}: {}", version_dir, e))?;
+
+            // The release asset is a raw per-platform executable, not an archive.
+            if let Err(e) = zed::download_file(
+                &asset.download_url,
+                &binary_path,
+                zed::DownloadedFileType::Uncompressed,
+            ) {
+                let error_msg = format!("failed to download lx-lsp release: {}", e);
+                zed::set_language_server_installation_status(
+                    language_server_id,
+                    &zed::LanguageServerInstallationStatus::Failed(error_msg.clone()),
+                );
+                return Err(error_msg.into());
+            }
+
+            if let Err(e) = zed::make_file_executable(&binary_path) {
+                let error_msg = format!("failed to make lx-lsp executable: {}", e);
+                zed::set_language_server_installation_status(
+                    language_server_id,
+                    &zed::LanguageServerInstallationStatus::Failed(error_msg.clone()),
+                );
+                return Err(error_msg.into());
+            }
+
+            if let Err(e) = self.persist_installed_version(&release.version) {
+                eprintln!("[LX Extension] Warning: {}", e);
+            }
+            self.prune_old_installs(&version_dir);
+        }
+
+        self.cached_version = Some(release.version.clone());
+
+        eprintln!("[LX Extension] Prebuilt binary ready at: {}", binary_path);
+
+        Ok(Some(binary_path))
+    }
+
     /// Install the language server using go install
-    fn install_language_server(&self, language_server_id: &LanguageServerId) -> Result<String> {
-        eprintln!("[LX Extension] Installing lx-lsp from github...");
+    fn install_via_go(
+        &self,
+        language_server_id: &LanguageServerId,
+        worktree: &Worktree,
+    ) -> Result<String> {
+        eprintln!("[LX Extension] Installing lx-lsp via go install...");
 
         zed::set_language_server_installation_status(
             language_server_id,
@@ -91,34 +250,13 @@ impl LxExtension {
             }
         }
 
-        // Use 'which' to find the installed binary
-        eprintln!("[LX Extension] Locating installed binary with 'which'...");
-
-        #[cfg(target_os = "windows")]
-        let which_cmd = "where";
-        #[cfg(not(target_os = "windows"))]
-        let which_cmd = "which";
-
-        let which_result = zed::Command::new(which_cmd)
-            .args(vec![self.get_binary_name().to_string()])
-            .output();
-
-        let binary_path = match which_result {
-            Ok(output) if output.status == Some(0) => {
-                let path = String::from_utf8_lossy(&output.stdout)
-                    .trim()
-                    .lines()
-                    .next()
-                    .unwrap_or("")
-                    .to_string();
+        // Locate the installed binary via the worktree's own environment first
+        // (mise/asdf shims, project-local PATH), matching `find_existing_binary`.
+        eprintln!("[LX Extension] Locating installed binary...");
 
-                if path.is_empty() {
-                    return Err("Failed to locate installed language server binary".into());
-                }
-
-                path
-            }
-            _ => {
+        let binary_path = match worktree.which(self.get_binary_name()) {
+            Some(path) => path,
+            None => {
                 // Fallback: Check default Go bin location ($HOME/go/bin)
                 if let Ok(home) = std::env::var("HOME") {
                     let go_bin_path = format!("{}/go/bin/{}", home, self.get_binary_name());
@@ -151,34 +289,15 @@ impl LxExtension {
         Ok(binary_path)
     }
 
-    /// Try to find an existing installation of the language server
-    fn find_existing_binary(&self) -> Option<String> {
+    /// Try to find an existing installation of the language server, preferring
+    /// whatever the worktree's own environment (PATH, mise/asdf shims, etc.)
+    /// resolves so project-local toolchains are respected.
+    fn find_existing_binary(&self, worktree: &Worktree) -> Option<String> {
         eprintln!("[LX Extension] Searching for existing binary...");
 
-        // Use 'which' to find the binary in PATH
-        #[cfg(target_os = "windows")]
-        let which_cmd = "where";
-        #[cfg(not(target_os = "windows"))]
-        let which_cmd = "which";
-
-        let which_result = zed::Command::new(which_cmd)
-            .args(vec![self.get_binary_name().to_string()])
-            .output();
-
-        if let Ok(output) = which_result {
-            if output.status == Some(0) {
-                let path = String::from_utf8_lossy(&output.stdout)
-                    .trim()
-                    .lines()
-                    .next()
-                    .unwrap_or("")
-                    .to_string();
-
-                if !path.is_empty() {
-                    eprintln!("[LX Extension] Found binary in PATH: {}", path);
-                    return Some(path);
-                }
-            }
+        if let Some(path) = worktree.which(self.get_binary_name()) {
+            eprintln!("[LX Extension] Found binary via worktree.which: {}", path);
+            return Some(path);
         }
 
         // Fallback: Check default Go bin location ($HOME/go/bin)
@@ -202,7 +321,7 @@ impl LxExtension {
     fn language_server_binary_path(
         &mut self,
         language_server_id: &LanguageServerId,
-        _worktree: &Worktree,
+        worktree: &Worktree,
     ) -> Result<String> {
         eprintln!("[LX Extension] ========== Starting language_server_binary_path ==========");
 
@@ -218,7 +337,7 @@ impl LxExtension {
         );
 
         // Try to find existing binary
-        if let Some(existing_path) = self.find_existing_binary() {
+        if let Some(existing_path) = self.find_existing_binary(worktree) {
             eprintln!("[LX Extension] ✓ Using existing binary: {}", existing_path);
             self.cached_binary_path = Some(existing_path.clone());
 
@@ -230,6 +349,20 @@ impl LxExtension {
             return Ok(existing_path);
         }
 
+        // Prefer a prebuilt binary from the latest GitHub release so that
+        // users without a Go toolchain get a working extension out of the box.
+        if let Some(binary_path) = self.install_prebuilt_binary(language_server_id)? {
+            self.cached_binary_path = Some(binary_path.clone());
+
+            zed::set_language_server_installation_status(
+                language_server_id,
+                &zed::LanguageServerInstallationStatus::None,
+            );
+
+            eprintln!("[LX Extension] ========== Finished successfully ==========");
+            return Ok(binary_path);
+        }
+
         // Check if Go is available
         if !self.check_go_available() {
             let error_msg = "Go toolchain not found. Please install Go to use LX extension.\n\n\
@@ -245,7 +378,7 @@ impl LxExtension {
         }
 
         // Install the language server
-        let binary_path = self.install_language_server(language_server_id)?;
+        let binary_path = self.install_via_go(language_server_id, worktree)?;
 
         // Cache the path
         self.cached_binary_path = Some(binary_path.clone());
@@ -265,16 +398,66 @@ impl zed::Extension for LxExtension {
         language_server_id: &LanguageServerId,
         worktree: &Worktree,
     ) -> Result<zed::Command> {
+        let binary_settings = LspSettings::for_worktree("lx-lsp", worktree)
+            .ok()
+            .and_then(|settings| settings.binary);
+
+        let args = binary_settings
+            .as_ref()
+            .and_then(|binary| binary.arguments.clone())
+            .unwrap_or_default();
+        let env = binary_settings
+            .as_ref()
+            .and_then(|binary| binary.env.clone())
+            .map(|env| env.into_iter().collect())
+            .unwrap_or_default();
+
+        // If the user has pointed us at their own lx-lsp build, skip
+        // discovery and installation entirely and launch it as configured.
+        if let Some(path) = binary_settings.as_ref().and_then(|binary| binary.path.clone()) {
+            eprintln!("[LX Extension] Using user-configured lx-lsp binary: {}", path);
+
+            return Ok(zed::Command {
+                command: path,
+                args,
+                env,
+            });
+        }
+
         let binary_path = self.language_server_binary_path(language_server_id, worktree)?;
 
         eprintln!("[LX Extension] Starting language server: {}", binary_path);
 
         Ok(zed::Command {
             command: binary_path,
-            args: vec![],
-            env: Default::default(),
+            args,
+            env,
         })
     }
+
+    fn language_server_initialization_options(
+        &mut self,
+        _language_server_id: &LanguageServerId,
+        worktree: &Worktree,
+    ) -> Result<Option<zed::serde_json::Value>> {
+        let settings = LspSettings::for_worktree("lx-lsp", worktree)
+            .ok()
+            .and_then(|settings| settings.initialization_options);
+
+        Ok(settings)
+    }
+
+    fn language_server_workspace_configuration(
+        &mut self,
+        _language_server_id: &LanguageServerId,
+        worktree: &Worktree,
+    ) -> Result<Option<zed::serde_json::Value>> {
+        let settings = LspSettings::for_worktree("lx-lsp", worktree)
+            .ok()
+            .and_then(|settings| settings.settings);
+
+        Ok(settings)
+    }
 }
 
 zed::register_extension!(LxExtension);